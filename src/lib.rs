@@ -5,7 +5,7 @@
 use std::any::{Any, TypeId};
 use std::mem;
 
-pub trait Key {
+pub trait Key: 'static {
     type Value: Any;
 }
 
@@ -23,10 +23,34 @@ pub trait RecursiveVariadic {
         }
     }
     /// Add the default value for N
-    fn and_default<N: Key>(self) -> Entry<N, Self> 
+    fn and_default<N: Key>(self) -> Entry<N, Self>
     where N::Value: Default, Self: Sized {
         self.and(N::Value::default())
     }
+    /// Call `f` with the key's `TypeId` and a `dyn Any` reference to the value
+    /// of every entry, without needing to name any of the `Key`s.
+    fn for_each(&self, f: &mut dyn FnMut(TypeId, &dyn Any));
+    /// Like `for_each`, but gives mutable access to each value.
+    fn for_each_mut(&mut self, f: &mut dyn FnMut(TypeId, &mut dyn Any));
+    /// Fold over every entry, threading an accumulator through in order.
+    fn fold<Acc>(&self, init: Acc, f: &mut dyn FnMut(Acc, TypeId, &dyn Any) -> Acc) -> Acc;
+    /// The number of entries in this chain.
+    fn len(&self) -> usize {
+        self.fold(0, &mut |acc, _, _| acc + 1)
+    }
+    /// Whether this chain has no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Remove the entry for `N`, yielding its value and the remaining chain
+    /// with that entry gone. `I` pins down which layer of the chain holds
+    /// `N` and should be left for inference, e.g. `chain.remove::<N, _>()`.
+    fn remove<N: Key, I>(self) -> (N::Value, <Self as Remove<N, I>>::Output)
+    where
+        Self: Remove<N, I> + Sized,
+    {
+        self.remove_entry()
+    }
 }
 
 /// The base case for recursive variadics: no fields.
@@ -34,6 +58,11 @@ pub type Empty = ();
 impl RecursiveVariadic for Empty {
     fn get<N: Key>(&self) -> Option<&N::Value> { None }
     fn get_mut<N: Key>(&mut self) -> Option<&mut N::Value> { None }
+    fn for_each(&self, _f: &mut dyn FnMut(TypeId, &dyn Any)) {}
+    fn for_each_mut(&mut self, _f: &mut dyn FnMut(TypeId, &mut dyn Any)) {}
+    fn fold<Acc>(&self, init: Acc, _f: &mut dyn FnMut(Acc, TypeId, &dyn Any) -> Acc) -> Acc {
+        init
+    }
 }
 
 /// Wraps some field data and a parent, which is either another Entry or Empty
@@ -43,20 +72,147 @@ pub struct Entry<T: Key, R> {
 }
 
 impl<T: Key, R: RecursiveVariadic> RecursiveVariadic for Entry<T, R> {
-    fn get<N: Key>(&self) -> Option<&N::Value> { 
-        if TypeId::of::<N::Value>() == TypeId::of::<T::Value>() {
+    fn get<N: Key>(&self) -> Option<&N::Value> {
+        if TypeId::of::<N>() == TypeId::of::<T>() {
+            // Safe because the keys match, so N::Value == T::Value, even though
+            // the compiler can't see that through the associated type.
             Some(unsafe { mem::transmute(&self.data) })
         } else {
             self.parent.get::<N>()
         }
     }
-    fn get_mut<N: Key>(&mut self) -> Option<&mut N::Value> { 
-        if TypeId::of::<N::Value>() == TypeId::of::<T::Value>() {
+    fn get_mut<N: Key>(&mut self) -> Option<&mut N::Value> {
+        if TypeId::of::<N>() == TypeId::of::<T>() {
+            // Safe because the keys match, so N::Value == T::Value, even though
+            // the compiler can't see that through the associated type.
             Some(unsafe { mem::transmute(&mut self.data) })
         } else {
             self.parent.get_mut::<N>()
         }
     }
+    fn for_each(&self, f: &mut dyn FnMut(TypeId, &dyn Any)) {
+        f(TypeId::of::<T>(), &self.data as &dyn Any);
+        self.parent.for_each(f);
+    }
+    fn for_each_mut(&mut self, f: &mut dyn FnMut(TypeId, &mut dyn Any)) {
+        f(TypeId::of::<T>(), &mut self.data as &mut dyn Any);
+        self.parent.for_each_mut(f);
+    }
+    fn fold<Acc>(&self, init: Acc, f: &mut dyn FnMut(Acc, TypeId, &dyn Any) -> Acc) -> Acc {
+        let acc = f(init, TypeId::of::<T>(), &self.data as &dyn Any);
+        self.parent.fold(acc, f)
+    }
+}
+
+/// Joins two chains into one at compile time, with `self`'s entries placed
+/// ahead of `other`'s.
+pub trait Concat<O: RecursiveVariadic> {
+    type Output: RecursiveVariadic;
+    fn concat(self, other: O) -> Self::Output;
+}
+
+impl<O: RecursiveVariadic> Concat<O> for Empty {
+    type Output = O;
+    fn concat(self, other: O) -> O {
+        other
+    }
+}
+
+impl<T: Key, R: Concat<O>, O: RecursiveVariadic> Concat<O> for Entry<T, R> {
+    type Output = Entry<T, R::Output>;
+    fn concat(self, other: O) -> Self::Output {
+        Entry {
+            data: self.data,
+            parent: self.parent.concat(other),
+        }
+    }
+}
+
+/// Flips the insertion order of a chain.
+pub trait Reverse {
+    type Output: RecursiveVariadic;
+    fn reverse(self) -> Self::Output;
+}
+
+/// Helper for `Reverse`: accumulates entries from `self` onto `Acc`, moving
+/// each one from the front of `self` to the front of the accumulator.
+pub trait ReverseInto<Acc: RecursiveVariadic> {
+    type Output: RecursiveVariadic;
+    fn reverse_into(self, acc: Acc) -> Self::Output;
+}
+
+impl<Acc: RecursiveVariadic> ReverseInto<Acc> for Empty {
+    type Output = Acc;
+    fn reverse_into(self, acc: Acc) -> Acc {
+        acc
+    }
+}
+
+impl<T: Key, R: ReverseInto<Entry<T, Acc>>, Acc: RecursiveVariadic> ReverseInto<Acc> for Entry<T, R> {
+    type Output = R::Output;
+    fn reverse_into(self, acc: Acc) -> Self::Output {
+        self.parent.reverse_into(Entry {
+            data: self.data,
+            parent: acc,
+        })
+    }
+}
+
+impl<X: ReverseInto<Empty>> Reverse for X {
+    type Output = X::Output;
+    fn reverse(self) -> Self::Output {
+        self.reverse_into(())
+    }
+}
+
+/// Unpacks a chain into the nested tuple of its values, most recently added
+/// first, discarding the `Key`s.
+pub trait IntoValueTuple {
+    type Output;
+    fn into_value_tuple(self) -> Self::Output;
+}
+
+impl IntoValueTuple for Empty {
+    type Output = ();
+    fn into_value_tuple(self) -> Self::Output {}
+}
+
+impl<T: Key, R: IntoValueTuple> IntoValueTuple for Entry<T, R> {
+    type Output = (T::Value, R::Output);
+    fn into_value_tuple(self) -> Self::Output {
+        (self.data, self.parent.into_value_tuple())
+    }
+}
+
+/// Marker indicating that a key was found at the current layer of a chain.
+pub struct Here;
+/// Marker indicating that a key was found one layer deeper than `I` says.
+pub struct There<I>(std::marker::PhantomData<I>);
+
+/// Implementation detail behind `RecursiveVariadic::remove`.
+///
+/// `I` disambiguates which layer of the chain holds `N`, since (unlike
+/// `get`, which always returns the same `Option<&N::Value>` type) the type
+/// of the remaining chain differs depending on where `N` was found, and the
+/// compiler can't pick that apart from `N` alone without specialization.
+pub trait Remove<N: Key, I> {
+    type Output: RecursiveVariadic;
+    fn remove_entry(self) -> (N::Value, Self::Output);
+}
+
+impl<N: Key, R: RecursiveVariadic> Remove<N, Here> for Entry<N, R> {
+    type Output = R;
+    fn remove_entry(self) -> (N::Value, R) {
+        (self.data, self.parent)
+    }
+}
+
+impl<N: Key, T: Key, R: Remove<N, I>, I> Remove<N, There<I>> for Entry<T, R> {
+    type Output = Entry<T, R::Output>;
+    fn remove_entry(self) -> (N::Value, Self::Output) {
+        let (val, rest) = self.parent.remove_entry();
+        (val, Entry { data: self.data, parent: rest })
+    }
 }
 
 #[cfg(test)]
@@ -65,6 +221,11 @@ mod tests {
 
     use super::*;
 
+    struct Width;
+    struct Height;
+    impl Key for Width { type Value = i32; }
+    impl Key for Height { type Value = i32; }
+
     #[test]
     fn it_works() {
         impl Key for i32 { type Value = i32; }
@@ -78,4 +239,85 @@ mod tests {
         assert!(thing.get::<&'static str>().is_some());
         assert!(thing.get::<bool>().is_none());
     }
+
+    #[test]
+    fn distinct_keys_same_value_type() {
+        let mut thing = ().and::<Width>(320).and::<Height>(240);
+        assert_eq!(*thing.get::<Width>().unwrap(), 320);
+        assert_eq!(*thing.get::<Height>().unwrap(), 240);
+
+        *thing.get_mut::<Height>().unwrap() = 480;
+        assert_eq!(*thing.get::<Width>().unwrap(), 320);
+        assert_eq!(*thing.get::<Height>().unwrap(), 480);
+    }
+
+    #[test]
+    fn for_each_visits_every_entry() {
+        let thing = ().and::<i32>(23).and_default::<usize>().and::<bool>(true);
+
+        assert_eq!(thing.len(), 3);
+
+        let mut seen = 0;
+        thing.for_each(&mut |_, _| seen += 1);
+        assert_eq!(seen, 3);
+
+        let count = thing.fold(0, &mut |acc, _, _| acc + 1);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn for_each_mut_can_modify_values() {
+        let mut thing = ().and::<i32>(23).and::<bool>(true);
+
+        thing.for_each_mut(&mut |_, any| {
+            if let Some(v) = any.downcast_mut::<i32>() {
+                *v += 1;
+            }
+        });
+
+        assert_eq!(*thing.get::<i32>().unwrap(), 24);
+    }
+
+    #[test]
+    fn concat_joins_two_chains() {
+        let a = ().and::<Width>(320);
+        let b = ().and::<Height>(240).and::<bool>(true);
+
+        let joined = a.concat(b);
+        assert_eq!(*joined.get::<Width>().unwrap(), 320);
+        assert_eq!(*joined.get::<Height>().unwrap(), 240);
+        assert!(*joined.get::<bool>().unwrap());
+        assert_eq!(joined.len(), 3);
+    }
+
+    #[test]
+    fn reverse_keeps_values_retrievable() {
+        let thing = ().and::<Width>(320).and::<Height>(240).and::<bool>(true);
+        let reversed = thing.reverse();
+
+        assert_eq!(*reversed.get::<Width>().unwrap(), 320);
+        assert_eq!(*reversed.get::<Height>().unwrap(), 240);
+        assert!(*reversed.get::<bool>().unwrap());
+        assert_eq!(reversed.len(), 3);
+    }
+
+    #[test]
+    fn into_value_tuple_destructures_the_chain() {
+        let thing = ().and::<i32>(23).and::<bool>(true);
+        let (flag, (num, ())) = thing.into_value_tuple();
+        assert_eq!(num, 23);
+        assert!(flag);
+    }
+
+    #[test]
+    fn remove_takes_the_value_and_shrinks_the_chain() {
+        let thing = ().and::<Width>(320).and::<Height>(240).and::<bool>(true);
+
+        let (height, rest) = thing.remove::<Height, _>();
+        assert_eq!(height, 240);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(*rest.get::<Width>().unwrap(), 320);
+        assert!(*rest.get::<bool>().unwrap());
+        assert!(rest.get::<Height>().is_none());
+    }
 }
\ No newline at end of file